@@ -1,15 +1,23 @@
+mod page_index;
+mod predicate;
+mod remote;
+mod schema_tree;
+
 use std::fs::File;
 
 use argh::FromArgs;
 use color_eyre::Result;
-use parquet::column::page::Page;
+use parquet::column::page::{Page, PageMetadata, PageReader};
 use parquet::file::{
-    metadata::{ParquetMetaData, ParquetMetaDataReader},
-    page_encoding_stats::PageEncodingStats,
+    metadata::{PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader},
     reader::FileReader,
     serialized_reader::SerializedFileReader,
     statistics::Statistics,
 };
+
+use page_index::{page_index_stats, page_index_text, PageIndexStats};
+use predicate::Predicate;
+use schema_tree::SchemaColumn;
 use ratatui::layout::Rect;
 use ratatui::style::palette::tailwind::{self, Palette};
 use ratatui::text::{Text, ToLine};
@@ -26,6 +34,7 @@ use ratatui::{
 #[derive(FromArgs, Clone)]
 /// Visualize metadata from one or more parquet files
 struct Args {
+    /// local paths, or s3://, gs://, https:// URLs, of parquet files to probe
     #[argh(positional, greedy)]
     paths: Vec<String>,
 
@@ -51,55 +60,218 @@ fn main() -> Result<()> {
     result
 }
 
+/// How many pages to keep in the column_area viewport at once. Pages are
+/// decoded in order up through the last one in this window, so pages
+/// scrolled past are decoded as well, and decoded pages stay cached.
+const VISIBLE_PAGE_WINDOW: usize = 32;
+
 struct ParqFile {
     path: String,
-    pages: Vec<Page>,
+    // Cheap, non-decoding peek of every page in the current column chunk -
+    // enough to lay out and label bars without buffering page data.
+    page_metas: Vec<PageMetadata>,
+    // Filled in lazily, only for pages that have scrolled into view.
+    page_cache: Vec<Option<Page>>,
+    page_stats: Vec<PageIndexStats>,
+    page_scroll: usize,
+    // Tracks how far `page_reader` has been consumed so far; pages are only
+    // decoded in order as they're first requested.
+    next_unread: usize,
+    // `None` when the current row group/column has no column chunk to read
+    // from - an empty file with zero row groups, for instance.
+    page_reader: Option<Box<dyn PageReader>>,
     current_row_group: usize,
     current_col: usize,
+    schema_columns: Vec<SchemaColumn>,
+    // Highlighted row in the schema tree panel; only applied to
+    // `current_col` when the user confirms it.
+    schema_cursor: usize,
     metadata_reader: ParquetMetaDataReader,
+    metadata: ParquetMetaData,
     reader: Box<dyn FileReader>,
 }
 
 impl ParqFile {
     fn new(path: &str) -> Self {
-        // read the parquet footer
-        let file = File::open(&path).expect(&format!("could not open {path}"));
-        let mut metadata_reader = ParquetMetaDataReader::new().with_page_indexes(true);
-        metadata_reader
-            .try_parse(&file)
-            .expect("could not parse file");
-        let metadata = metadata_reader.finish().expect("could not finish file");
-
-        let reader = SerializedFileReader::new(file).expect("could not create reader");
+        let (metadata_reader, metadata, reader) = match remote::classify(path) {
+            remote::Source::Local(path) => {
+                let file = File::open(&path).expect(&format!("could not open {path}"));
+                let mut metadata_reader =
+                    ParquetMetaDataReader::new().with_page_index_policy(PageIndexPolicy::Optional);
+                metadata_reader
+                    .try_parse(&file)
+                    .expect("could not parse file");
+                let metadata = metadata_reader.finish().expect("could not finish file");
+                let reader: Box<dyn FileReader> = Box::new(
+                    SerializedFileReader::new(file).expect("could not create reader"),
+                );
+                (metadata_reader, metadata, reader)
+            }
+            remote::Source::Remote(remote_source) => {
+                // Only the footer + page index suffix get fetched here -
+                // `ChunkReader::get_bytes`/`get_read` on `RemoteSource` turn
+                // into individual object-store range requests.
+                let mut metadata_reader =
+                    ParquetMetaDataReader::new().with_page_index_policy(PageIndexPolicy::Optional);
+                metadata_reader
+                    .try_parse(&remote_source)
+                    .expect("could not fetch/parse remote footer");
+                let metadata = metadata_reader.finish().expect("could not finish file");
+                let reader: Box<dyn FileReader> = Box::new(
+                    SerializedFileReader::new(remote_source).expect("could not create reader"),
+                );
+                (metadata_reader, metadata, reader)
+            }
+        };
 
-        let pages = Self::get_pages(&reader, 0, 0);
+        let schema_columns = schema_tree::schema_columns(&metadata);
+        let page_reader = if metadata.num_row_groups() > 0 && !schema_columns.is_empty() {
+            Some(Self::column_page_reader(reader.as_ref(), 0, 0))
+        } else {
+            None
+        };
 
-        Self {
+        let mut pf = Self {
             path: path.into(),
-            pages,
+            page_metas: Vec::new(),
+            page_cache: Vec::new(),
+            page_stats: Vec::new(),
+            page_scroll: 0,
+            next_unread: 0,
+            page_reader,
             current_row_group: 0,
             current_col: 0,
+            schema_columns,
+            schema_cursor: 0,
             metadata_reader,
-            reader: Box::new(reader),
-        }
+            metadata,
+            reader,
+        };
+        pf.reload_pages();
+        pf
     }
 
-    fn reload_pages(&mut self) {
-        self.pages = Self::get_pages(
-            self.reader.as_ref(),
-            self.current_row_group,
-            self.current_col,
-        );
+    fn num_row_groups(&self) -> usize {
+        self.metadata.num_row_groups()
     }
 
-    fn get_pages(reader: &dyn FileReader, row_group_num: usize, col_num: usize) -> Vec<Page> {
+    fn num_columns(&self) -> usize {
+        self.schema_columns.len()
+    }
+
+    fn column_page_reader(
+        reader: &dyn FileReader,
+        row_group_num: usize,
+        col_num: usize,
+    ) -> Box<dyn PageReader> {
         reader
             .get_row_group(row_group_num)
             .expect("couldn't read row group")
             .get_column_page_reader(col_num)
             .expect("couldn't get column page reader")
-            .collect::<Result<Vec<Page>, _>>()
-            .expect("couldn't read pages")
+    }
+
+    fn reload_pages(&mut self) {
+        if self.num_row_groups() == 0 || self.num_columns() == 0 {
+            self.page_metas = Vec::new();
+            self.page_cache = Vec::new();
+            self.page_scroll = 0;
+            self.next_unread = 0;
+            self.page_reader = None;
+            self.page_stats = Vec::new();
+            return;
+        }
+
+        self.page_metas = Self::peek_page_metas(
+            self.reader.as_ref(),
+            self.current_row_group,
+            self.current_col,
+        );
+        self.page_cache = vec![None; self.page_metas.len()];
+        self.page_scroll = 0;
+        self.next_unread = 0;
+        self.page_reader = Some(Self::column_page_reader(
+            self.reader.as_ref(),
+            self.current_row_group,
+            self.current_col,
+        ));
+        self.page_stats =
+            page_index_stats(&self.metadata, self.current_row_group, self.current_col);
+        // The column/offset index only ever cover data pages, never the
+        // dictionary page, so `page_stats` would otherwise be one entry
+        // short and off-by-one against `page_metas`/`page_cache` for any
+        // dictionary-encoded column chunk.
+        if self.page_metas.first().is_some_and(|m| m.is_dict) {
+            self.page_stats.insert(0, PageIndexStats::default());
+        }
+    }
+
+    /// Walks the column chunk once, recording each page's `PageMetadata`
+    /// without decompressing or buffering it (`skip_next_page` moves past
+    /// the page instead of materializing it).
+    fn peek_page_metas(
+        reader: &dyn FileReader,
+        row_group_num: usize,
+        col_num: usize,
+    ) -> Vec<PageMetadata> {
+        let mut page_reader = Self::column_page_reader(reader, row_group_num, col_num);
+        let mut metas = Vec::new();
+        while let Some(meta) = page_reader
+            .peek_next_page()
+            .expect("couldn't peek next page")
+        {
+            metas.push(meta);
+            page_reader.skip_next_page().expect("couldn't skip page");
+        }
+        metas
+    }
+
+    /// Decodes pages up through `idx`, if they haven't been already. A
+    /// no-op when there's no column chunk to read from (see `page_reader`).
+    fn ensure_loaded(&mut self, idx: usize) {
+        while self.next_unread <= idx {
+            let Some(page_reader) = self.page_reader.as_mut() else {
+                return;
+            };
+            let page = page_reader
+                .get_next_page()
+                .expect("couldn't read page")
+                .expect("page reader exhausted before expected page");
+            self.page_cache[self.next_unread] = Some(page);
+            self.next_unread += 1;
+        }
+    }
+
+    /// Cheap size to use for bar layout: prefers the offset index's
+    /// compressed page size, then the decoded buffer length for pages that
+    /// have already been materialized, then the row count from the
+    /// `PageMetadata` peek (no page index and a data page that hasn't been
+    /// decoded yet; the peek only carries a row count for V2 data pages).
+    /// Once none of those are available - as for a dictionary page that
+    /// hasn't been decoded yet - the page collapses to a width of 1.
+    fn page_size(&self, idx: usize) -> u32 {
+        self.page_stats
+            .get(idx)
+            .and_then(|s| s.compressed_size)
+            .map(|n| n as u32)
+            .or_else(|| {
+                self.page_cache
+                    .get(idx)
+                    .and_then(|p| p.as_ref())
+                    .map(|p| p.buffer().len() as u32)
+            })
+            .or_else(|| {
+                self.page_metas
+                    .get(idx)
+                    .and_then(|m| m.num_rows)
+                    .map(|n| n as u32)
+            })
+            .unwrap_or(1)
+    }
+
+    fn visible_range(&self) -> std::ops::Range<usize> {
+        let end = (self.page_scroll + VISIBLE_PAGE_WINDOW).min(self.page_metas.len());
+        self.page_scroll..end
     }
 }
 
@@ -110,6 +282,12 @@ struct App {
     palettes: Vec<Palette>,
     max_col_display_length: u32,
     focused_file: usize,
+    // `Some` while the user is typing a predicate; committed into
+    // `predicate` on Enter.
+    predicate_input: Option<String>,
+    predicate: Option<Predicate>,
+    // Whether the schema tree panel is open for the focused file.
+    schema_panel_open: bool,
 }
 
 impl App {
@@ -127,11 +305,14 @@ impl App {
             ],
             max_col_display_length: 0,
             focused_file: 0,
+            predicate_input: None,
+            predicate: None,
+            schema_panel_open: false,
         };
 
         app.files.iter_mut().for_each(|pf| {
-            pf.current_row_group = args.row_group;
-            pf.current_col = args.column;
+            pf.current_row_group = args.row_group.min(pf.num_row_groups().saturating_sub(1));
+            pf.current_col = args.column.min(pf.num_columns().saturating_sub(1));
             pf.reload_pages();
         });
         app.recalculate();
@@ -143,13 +324,13 @@ impl App {
             .files
             .iter()
             .map(|pf| {
-                pf.pages
-                    .iter()
-                    .map(|page| page.buffer().len())
-                    .sum::<usize>()
+                pf.visible_range()
+                    .map(|i| pf.page_size(i) as u64)
+                    .sum::<u64>()
             })
             .max()
-            .expect("cannot calculate display height") as u32;
+            .expect("cannot calculate display height")
+            .max(1) as u32;
     }
 
     fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
@@ -162,37 +343,99 @@ impl App {
 
     fn handle_events(&mut self) -> Result<()> {
         if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                return Ok(());
+            }
+
+            if let Some(input) = &mut self.predicate_input {
+                match key.code {
+                    KeyCode::Enter => {
+                        self.predicate = predicate::parse_predicate(input);
+                        self.predicate_input = None;
+                    }
+                    KeyCode::Esc => self.predicate_input = None,
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Char(c) => input.push(c),
+                    _ => {}
+                }
+                return Ok(());
+            }
+
+            if self.schema_panel_open {
+                let pf = &mut self.files[self.focused_file];
+                match key.code {
+                    KeyCode::Up => pf.schema_cursor = pf.schema_cursor.saturating_sub(1),
+                    KeyCode::Down => {
+                        pf.schema_cursor =
+                            (pf.schema_cursor + 1).min(pf.num_columns().saturating_sub(1))
+                    }
+                    KeyCode::Enter => {
+                        pf.current_col = pf.schema_cursor;
+                        pf.reload_pages();
+                        self.schema_panel_open = false;
+                        self.predicate = None;
+                        self.predicate_input = None;
+                        self.recalculate();
+                    }
+                    KeyCode::Esc | KeyCode::Char('t') => self.schema_panel_open = false,
+                    _ => {}
+                }
+                return Ok(());
+            }
+
             let pf = &mut self.files[self.focused_file];
 
             if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('q') {
                 self.should_exit = true;
             } else if key.kind == KeyEventKind::Press && key.code == KeyCode::Esc {
                 self.should_exit = true;
+            } else if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('t') {
+                pf.schema_cursor = pf.current_col;
+                self.schema_panel_open = true;
             } else if key.kind == KeyEventKind::Press && key.code == KeyCode::Up {
-                pf.current_row_group += 1;
+                pf.current_row_group =
+                    (pf.current_row_group + 1).min(pf.num_row_groups().saturating_sub(1));
                 pf.reload_pages();
                 self.recalculate();
             } else if key.kind == KeyEventKind::Press && key.code == KeyCode::Down {
-                pf.current_row_group -= 1;
+                pf.current_row_group = pf.current_row_group.saturating_sub(1);
                 pf.reload_pages();
                 self.recalculate();
             } else if key.kind == KeyEventKind::Press && key.code == KeyCode::Left {
-                pf.current_col -= 1;
+                pf.current_col = pf.current_col.saturating_sub(1);
                 pf.reload_pages();
+                self.predicate = None;
+                self.predicate_input = None;
                 self.recalculate();
             } else if key.kind == KeyEventKind::Press && key.code == KeyCode::Right {
-                pf.current_col += 1;
+                pf.current_col = (pf.current_col + 1).min(pf.num_columns().saturating_sub(1));
                 pf.reload_pages();
+                self.predicate = None;
+                self.predicate_input = None;
+                self.recalculate();
+            } else if key.kind == KeyEventKind::Press && key.code == KeyCode::PageDown {
+                let max_scroll = pf.page_metas.len().saturating_sub(1);
+                pf.page_scroll = (pf.page_scroll + VISIBLE_PAGE_WINDOW).min(max_scroll);
+                self.recalculate();
+            } else if key.kind == KeyEventKind::Press && key.code == KeyCode::PageUp {
+                pf.page_scroll = pf.page_scroll.saturating_sub(VISIBLE_PAGE_WINDOW);
+                self.recalculate();
             } else if key.kind == KeyEventKind::Press && key.code == KeyCode::Tab {
                 self.focused_file = (self.focused_file + 1) % self.files.len();
+            } else if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('p') {
+                self.predicate_input = Some(String::new());
+            } else if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('c') {
+                self.predicate = None;
             }
         }
         Ok(())
     }
 
-    fn draw(&self, frame: &mut Frame) {
+    fn draw(&mut self, frame: &mut Frame) {
         use Constraint::{Fill, Length, Min, Percentage, Ratio};
-        let vertical = Layout::vertical([Length(4), Fill(1)]).spacing(1);
+        let vertical = Layout::vertical([Length(5), Fill(1)]).spacing(1);
         let [header_area, center_area] = vertical.areas(frame.area());
 
         let labels = ["A", "B", "C", "D", "E"];
@@ -206,23 +449,38 @@ impl App {
         //let title_area = block.inner(header_area);
         //frame.render_widget(block, header_area);
 
-        let file_header = Paragraph::new(
-            self.files
-                .iter()
-                .enumerate()
-                .map(|(i, pf)| {
-                    Line::from(format!("File {}: {}", labels[i], pf.path))
-                        .bg(self.palettes[i].c900)
-                        .fg(self.palettes[i].c100)
-                })
-                .collect::<Vec<_>>(),
-        );
+        let mut header_lines: Vec<Line> = self
+            .files
+            .iter()
+            .enumerate()
+            .map(|(i, pf)| {
+                Line::from(format!("File {}: {}", labels[i], pf.path))
+                    .bg(self.palettes[i].c900)
+                    .fg(self.palettes[i].c100)
+            })
+            .collect();
+
+        header_lines.push(self.predicate_status_line());
+
+        let file_header = Paragraph::new(header_lines);
 
         let block_area = header.inner(header_area);
 
         frame.render_widget(header, header_area);
         frame.render_widget(file_header, block_area);
 
+        let (tree_area, files_area) = if self.schema_panel_open {
+            let [tree_area, files_area] =
+                Layout::horizontal([Percentage(25), Fill(1)]).spacing(1).areas(center_area);
+            (Some(tree_area), files_area)
+        } else {
+            (None, center_area)
+        };
+
+        if let Some(tree_area) = tree_area {
+            self.draw_schema_tree(tree_area, frame);
+        }
+
         let constraints = self
             .files
             .iter()
@@ -231,21 +489,45 @@ impl App {
 
         let horizontal = Layout::horizontal(constraints).spacing(1);
 
+        let max_col_display_length = self.max_col_display_length;
+        let focused_file = self.focused_file;
+        let predicate = self.predicate.clone();
+
         horizontal
-            .split(center_area)
+            .split(files_area)
             .into_iter()
             .enumerate()
             .for_each(|(i, column_area)| {
+                let scan_summary = predicate
+                    .as_ref()
+                    .map(|p| scan_summary_text(p, &self.files[i].page_stats))
+                    .unwrap_or_default();
+
+                let pf = &self.files[i];
+                let rg_summary = if pf.num_row_groups() > 0 {
+                    schema_tree::row_group_summary(&pf.metadata, pf.current_row_group)
+                } else {
+                    "no row groups".to_string()
+                };
+                let compression = if pf.num_row_groups() > 0 && pf.num_columns() > 0 {
+                    schema_tree::column_compression(&pf.metadata, pf.current_row_group, pf.current_col)
+                } else {
+                    "n/a".to_string()
+                };
+
                 let mut title = Line::from(format!(
-                    "   File:{} Row Group: {} Column: {} Pages:{}  ",
+                    "   File:{} RG:{} [{}] Col:{} ({}) Pages:{}{}  ",
                     labels[i].bold(),
-                    self.files[i].current_row_group,
-                    self.files[i].current_col,
-                    self.files[i].pages.len(),
+                    pf.current_row_group,
+                    rg_summary,
+                    pf.current_col,
+                    compression,
+                    pf.page_metas.len(),
+                    scan_summary,
                 ))
                 .centered();
 
-                if (i == self.focused_file) {
+                if i == focused_file {
                     title = title.bg(self.palettes[i].c900);
                 }
 
@@ -257,55 +539,132 @@ impl App {
                 let inner = block.inner(*column_area);
                 frame.render_widget(block, *column_area);
 
-                self.draw_column(&self.files[i], &self.palettes[i], inner, frame)
+                Self::draw_column(
+                    &mut self.files[i],
+                    &self.palettes[i],
+                    max_col_display_length,
+                    predicate.as_ref(),
+                    inner,
+                    frame,
+                )
             });
     }
 
+    /// Left-hand panel listing the focused file's leaf columns by path so
+    /// the user can pick one by name; Up/Down move `schema_cursor`, Enter
+    /// commits it to `current_col`.
+    fn draw_schema_tree(&self, area: Rect, frame: &mut Frame) {
+        let pf = &self.files[self.focused_file];
+
+        let block = Block::bordered().title("Schema (Enter: select, Esc: close)");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let lines: Vec<Line> = pf
+            .schema_columns
+            .iter()
+            .enumerate()
+            .map(|(i, col)| {
+                let line = Line::from(format!(
+                    "{} [{}] rep:{} def:{}",
+                    col.path, col.physical_type, col.max_rep_level, col.max_def_level
+                ));
+                if i == pf.schema_cursor {
+                    line.bg(self.palettes[self.focused_file].c800)
+                } else {
+                    line
+                }
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), inner);
+    }
+
+    fn predicate_status_line(&self) -> Line<'static> {
+        match (&self.predicate_input, &self.predicate) {
+            (Some(input), _) => Line::from(format!("predicate (Enter to apply, Esc to cancel): {input}")),
+            (None, Some(predicate)) => Line::from(format!(
+                "predicate: {:?} {} (p: edit, c: clear)",
+                predicate.op, predicate.value
+            )),
+            (None, None) => Line::from("predicate: none (p: enter a predicate like `col > 100`)"),
+        }
+    }
+
     fn draw_column(
-        &self,
-        parqfile: &ParqFile,
+        parqfile: &mut ParqFile,
         palette: &Palette,
+        max_col_display_length: u32,
+        predicate: Option<&Predicate>,
         column_area: Rect,
         frame: &mut Frame,
     ) {
         use Constraint::{Fill, Length, Min, Percentage, Ratio};
+        let visible_range = parqfile.visible_range();
         // fix me, check that page size doesn't overflow u16
-        let constraints = parqfile
-            .pages
-            .iter()
-            .map(|page| Ratio(page.buffer().len() as u32, self.max_col_display_length));
+        let constraints: Vec<_> = visible_range
+            .clone()
+            .map(|i| Ratio(parqfile.page_size(i), max_col_display_length))
+            .collect();
 
         let colors = [palette.c950, palette.c800];
         let foregrounds = [palette.c100];
 
         let vertical = Layout::vertical(constraints).spacing(0);
-        vertical
-            .split(column_area)
-            .into_iter()
-            .enumerate()
-            .for_each(|(i, page_area)| {
-                let page = &parqfile.pages[i];
-                let [left, right] = Layout::horizontal([Percentage(20), Percentage(80)])
-                    .spacing(1)
-                    .areas(*page_area);
-                //let block_left = Block::new().bg(colors[i % colors.len()]);
-                let block_left = Paragraph::new(format!("#{} {}b", i, page.buffer().len()))
-                    .bg(colors[i % colors.len()])
-                    .fg(foregrounds[i % foregrounds.len()]);
-                let right_content = page_text(page);
-                frame.render_widget(block_left, left);
-                frame.render_widget(right_content, right);
-            });
+        let page_areas = vertical.split(column_area);
+        for (slot, idx) in visible_range.enumerate() {
+            parqfile.ensure_loaded(idx);
+            let page_area = page_areas[slot];
+            let [left, right] = Layout::horizontal([Percentage(20), Percentage(80)])
+                .spacing(1)
+                .areas(page_area);
+            let page = parqfile.page_cache[idx]
+                .as_ref()
+                .expect("page was just ensured loaded");
+
+            let would_read = predicate
+                .zip(parqfile.page_stats.get(idx))
+                .is_none_or(|(p, stats)| predicate::would_read(p, stats));
+
+            //let block_left = Block::new().bg(colors[i % colors.len()]);
+            let mut block_left = Paragraph::new(format!("#{} {}b", idx, page.buffer().len()))
+                .bg(colors[idx % colors.len()]);
+            let mut lines = vec![Line::from(page_text(page))];
+            if let Some(stats) = parqfile.page_stats.get(idx) {
+                lines.push(Line::from(page_index_text(stats)));
+            }
+
+            if predicate.is_some() {
+                let (bar_fg, text_fg) = if would_read {
+                    (palette.c300, palette.c100)
+                } else {
+                    (palette.c950, palette.c700)
+                };
+                block_left = block_left.fg(bar_fg);
+                lines.push(Line::from(if would_read { "would read" } else { "would skip" }).fg(text_fg));
+            } else {
+                block_left = block_left.fg(foregrounds[idx % foregrounds.len()]);
+            }
+
+            let right_content = Paragraph::new(lines).wrap(Wrap { trim: true });
+            frame.render_widget(block_left, left);
+            frame.render_widget(right_content, right);
+        }
     }
 }
 
-fn page_text(page: &Page) -> Paragraph {
-    Paragraph::new(match page {
+fn scan_summary_text(predicate: &Predicate, stats: &[PageIndexStats]) -> String {
+    let decisions: Vec<bool> = stats.iter().map(|s| predicate::would_read(predicate, s)).collect();
+    let (read, skipped) = predicate::bytes_read_skipped(stats, &decisions);
+    format!(" | scan: {read}b read, {skipped}b skipped")
+}
+
+fn page_text(page: &Page) -> String {
+    match page {
         Page::DataPage { .. } => data_page_text(page),
         Page::DataPageV2 { .. } => format!("DataPageV2\n"),
         Page::DictionaryPage { .. } => dict_page_text(page),
-    })
-    .wrap(Wrap { trim: true })
+    }
 }
 
 fn dict_page_text(page: &Page) -> String {
@@ -0,0 +1,191 @@
+//! Reads the Column Index and Offset Index straight out of `ParquetMetaData`
+//! so we can show per-page min/max/null/byte-range stats without decoding
+//! the page itself (works even for V2 pages and pages with no embedded
+//! `Statistics`).
+
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::page_index::column_index::ColumnIndexMetaData;
+use parquet::file::page_index::offset_index::OffsetIndexMetaData;
+
+/// Stats for a single page, assembled by zipping the column index's
+/// per-page min/max/null_count with the offset index's `PageLocation`
+/// entries - both are positional, lining up with the column chunk's data
+/// pages by page ordinal. Neither index has an entry for the dictionary
+/// page.
+#[derive(Debug, Clone, Default)]
+pub struct PageIndexStats {
+    pub min: Option<String>,
+    pub max: Option<String>,
+    pub null_count: Option<i64>,
+    pub offset: Option<i64>,
+    pub compressed_size: Option<i32>,
+    pub first_row_index: Option<i64>,
+}
+
+impl PageIndexStats {
+    pub fn is_empty(&self) -> bool {
+        self.min.is_none()
+            && self.max.is_none()
+            && self.null_count.is_none()
+            && self.offset.is_none()
+    }
+}
+
+/// Per-page stats for every data page of `(row_group, col)`, in page order
+/// (the dictionary page, if any, has no entry). Empty
+/// if the file was opened without a page index, or the column has none.
+pub fn page_index_stats(
+    metadata: &ParquetMetaData,
+    row_group: usize,
+    col: usize,
+) -> Vec<PageIndexStats> {
+    let mut by_page: Vec<PageIndexStats> = Vec::new();
+
+    if let Some(column_index) = metadata.column_index() {
+        if let Some(index) = column_index.get(row_group).and_then(|rg| rg.get(col)) {
+            for (min, max, null_count) in index_min_max_nulls(index) {
+                by_page.push(PageIndexStats {
+                    min,
+                    max,
+                    null_count,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    if let Some(offset_index) = metadata.offset_index() {
+        if let Some(oi) = offset_index.get(row_group).and_then(|rg| rg.get(col)) {
+            let locations = page_locations(oi);
+            by_page.resize_with(by_page.len().max(locations.len()), Default::default);
+            for (stats, location) in by_page.iter_mut().zip(locations) {
+                stats.offset = Some(location.offset);
+                stats.compressed_size = Some(location.compressed_page_size);
+                stats.first_row_index = Some(location.first_row_index);
+            }
+        }
+    }
+
+    by_page
+}
+
+fn page_locations(oi: &OffsetIndexMetaData) -> &[parquet::file::page_index::offset_index::PageLocation] {
+    &oi.page_locations
+}
+
+/// `ColumnIndexMetaData` is generic over the column's physical type; pull
+/// the per-page min/max/null_count out regardless of which variant it is,
+/// stringifying the min/max so callers don't need to juggle a dozen
+/// concrete types. `BYTE_ARRAY`/`FIXED_LEN_BYTE_ARRAY` min/max are only kept
+/// when they're valid UTF-8: those physical types commonly back packed
+/// binary encodings (big-endian `DECIMAL`, raw bytes) that would otherwise
+/// get mangled into misleading replacement-character strings, which
+/// `would_read` would then happily numeric- or lexicographically-compare as
+/// if they meant something.
+fn index_min_max_nulls(
+    index: &ColumnIndexMetaData,
+) -> Vec<(Option<String>, Option<String>, Option<i64>)> {
+    macro_rules! extract {
+        ($native_index:expr, |$v:ident| $to_string:expr) => {
+            (0..$native_index.num_pages() as usize)
+                .map(|page| {
+                    (
+                        $native_index.min_value(page).map(|$v| $to_string),
+                        $native_index.max_value(page).map(|$v| $to_string),
+                        $native_index.null_count(page),
+                    )
+                })
+                .collect()
+        };
+    }
+
+    match index {
+        ColumnIndexMetaData::NONE => Vec::new(),
+        ColumnIndexMetaData::BOOLEAN(idx) => extract!(idx, |v| v.to_string()),
+        ColumnIndexMetaData::INT32(idx) => extract!(idx, |v| v.to_string()),
+        ColumnIndexMetaData::INT64(idx) => extract!(idx, |v| v.to_string()),
+        ColumnIndexMetaData::INT96(idx) => extract!(idx, |v| v.to_string()),
+        ColumnIndexMetaData::FLOAT(idx) => extract!(idx, |v| v.to_string()),
+        ColumnIndexMetaData::DOUBLE(idx) => extract!(idx, |v| v.to_string()),
+        ColumnIndexMetaData::BYTE_ARRAY(idx) => (0..idx.num_pages() as usize)
+            .map(|page| {
+                (
+                    idx.min_value(page).and_then(|v| utf8_bytes(v)),
+                    idx.max_value(page).and_then(|v| utf8_bytes(v)),
+                    idx.null_count(page),
+                )
+            })
+            .collect(),
+        ColumnIndexMetaData::FIXED_LEN_BYTE_ARRAY(idx) => (0..idx.num_pages() as usize)
+            .map(|page| {
+                (
+                    idx.min_value(page).and_then(|v| utf8_bytes(v)),
+                    idx.max_value(page).and_then(|v| utf8_bytes(v)),
+                    idx.null_count(page),
+                )
+            })
+            .collect(),
+    }
+}
+
+/// `None` for byte strings that aren't valid UTF-8 - e.g. packed big-endian
+/// `DECIMAL` values - rather than lossily decoding them into a string that
+/// looks meaningful but isn't.
+fn utf8_bytes(v: &[u8]) -> Option<String> {
+    std::str::from_utf8(v).ok().map(str::to_string)
+}
+
+pub fn page_index_text(stats: &PageIndexStats) -> String {
+    if stats.is_empty() {
+        return "page index: n/a".into();
+    }
+
+    format!(
+        "min:{} max:{} nulls:{} off:{} csize:{}",
+        stats.min.as_deref().unwrap_or("n/a"),
+        stats.max.as_deref().unwrap_or("n/a"),
+        stats
+            .null_count
+            .map_or("n/a".to_string(), |n| n.to_string()),
+        stats.offset.map_or("n/a".to_string(), |n| n.to_string()),
+        stats
+            .compressed_size
+            .map_or("n/a".to_string(), |n| n.to_string()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf8_bytes_keeps_valid_utf8() {
+        assert_eq!(utf8_bytes("hello".as_bytes()), Some("hello".to_string()));
+    }
+
+    #[test]
+    fn utf8_bytes_rejects_non_utf8_byte_arrays() {
+        // A packed big-endian DECIMAL or other raw binary value won't
+        // generally be valid UTF-8 - `None` keeps callers from lossily
+        // decoding it into a misleading string.
+        assert_eq!(utf8_bytes(&[0xFF, 0xFE, 0x00, 0x01]), None);
+    }
+
+    #[test]
+    fn page_index_text_reports_n_a_for_empty_stats() {
+        assert_eq!(page_index_text(&PageIndexStats::default()), "page index: n/a");
+    }
+
+    #[test]
+    fn page_index_text_reports_n_a_per_missing_field() {
+        let stats = PageIndexStats {
+            min: Some("1".into()),
+            max: Some("9".into()),
+            null_count: None,
+            offset: Some(128),
+            compressed_size: None,
+            first_row_index: None,
+        };
+        assert_eq!(page_index_text(&stats), "min:1 max:9 nulls:n/a off:128 csize:n/a");
+    }
+}
@@ -0,0 +1,191 @@
+//! Simulates predicate pushdown: given a simple `col <op> value` predicate
+//! on the currently selected column, decide which pages a scan would read
+//! vs. skip using only the Column Index's min/max per page - the same
+//! decision a real reader makes before touching any page bytes.
+
+use crate::page_index::PageIndexStats;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+#[derive(Debug, Clone)]
+pub struct Predicate {
+    pub op: Op,
+    pub value: String,
+}
+
+/// Parses predicates of the form `col > 100`, `col <= 3.5`, `col = 'foo'`.
+/// The column name itself is ignored - it's implied by whichever column is
+/// currently selected - only the operator and value are kept.
+pub fn parse_predicate(input: &str) -> Option<Predicate> {
+    let input = input.trim();
+    let ops: &[(&str, Op)] = &[
+        (">=", Op::Ge),
+        ("<=", Op::Le),
+        ("!=", Op::Ne),
+        (">", Op::Gt),
+        ("<", Op::Lt),
+        ("=", Op::Eq),
+    ];
+
+    // Only look for the operator ahead of any quote, so a quoted value like
+    // `col = 'a<b'` doesn't get mis-split on the `<` inside the string.
+    let unquoted = &input[..input.find(['\'', '"']).unwrap_or(input.len())];
+
+    for (token, op) in ops {
+        if let Some(idx) = unquoted.find(token) {
+            let value = input[idx + token.len()..].trim().trim_matches('\'').trim_matches('"');
+            if value.is_empty() {
+                return None;
+            }
+            return Some(Predicate {
+                op: *op,
+                value: value.to_string(),
+            });
+        }
+    }
+    None
+}
+
+/// Whether a scan applying `predicate` would read this page. Pages without
+/// page-index min/max can't be proven safe to skip, so they're always
+/// included, same as a page with nulls that could satisfy the predicate.
+pub fn would_read(predicate: &Predicate, stats: &PageIndexStats) -> bool {
+    let (Some(min), Some(max)) = (stats.min.as_deref(), stats.max.as_deref()) else {
+        return true;
+    };
+
+    match (parse_numeric(min), parse_numeric(max), parse_numeric(&predicate.value)) {
+        (Some(min), Some(max), Some(value)) => match predicate.op {
+            Op::Gt => max > value,
+            Op::Ge => max >= value,
+            Op::Lt => min < value,
+            Op::Le => min <= value,
+            Op::Eq => min <= value && value <= max,
+            Op::Ne => !(min == max && min == value),
+        },
+        _ => match predicate.op {
+            Op::Gt => max > predicate.value.as_str(),
+            Op::Ge => max >= predicate.value.as_str(),
+            Op::Lt => min < predicate.value.as_str(),
+            Op::Le => min <= predicate.value.as_str(),
+            Op::Eq => min <= predicate.value.as_str() && predicate.value.as_str() <= max,
+            Op::Ne => !(min == max && min == predicate.value),
+        },
+    }
+}
+
+fn parse_numeric(s: &str) -> Option<f64> {
+    s.parse::<f64>().ok()
+}
+
+/// Bytes a scan would read vs. skip for the given per-page decisions,
+/// falling back to 0 for pages whose offset index size isn't known.
+pub fn bytes_read_skipped(stats: &[PageIndexStats], decisions: &[bool]) -> (u64, u64) {
+    let mut read = 0u64;
+    let mut skipped = 0u64;
+    for (s, &would_read) in stats.iter().zip(decisions) {
+        let size = s.compressed_size.unwrap_or(0) as u64;
+        if would_read {
+            read += size;
+        } else {
+            skipped += size;
+        }
+    }
+    (read, skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(min: &str, max: &str) -> PageIndexStats {
+        PageIndexStats {
+            min: Some(min.to_string()),
+            max: Some(max.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse_predicate_picks_first_matching_operator() {
+        let p = parse_predicate("col >= 100").unwrap();
+        assert_eq!(p.op, Op::Ge);
+        assert_eq!(p.value, "100");
+    }
+
+    #[test]
+    fn parse_predicate_ignores_operators_inside_quotes() {
+        let p = parse_predicate("col = 'a<b'").unwrap();
+        assert_eq!(p.op, Op::Eq);
+        assert_eq!(p.value, "a<b");
+    }
+
+    #[test]
+    fn parse_predicate_strips_matching_quotes_from_value() {
+        assert_eq!(parse_predicate("col = \"foo\"").unwrap().value, "foo");
+    }
+
+    #[test]
+    fn parse_predicate_rejects_empty_value() {
+        assert!(parse_predicate("col =").is_none());
+    }
+
+    #[test]
+    fn parse_predicate_rejects_unrecognized_input() {
+        assert!(parse_predicate("col").is_none());
+    }
+
+    #[test]
+    fn would_read_without_min_max_always_reads() {
+        let p = Predicate { op: Op::Eq, value: "5".into() };
+        assert!(would_read(&p, &PageIndexStats::default()));
+    }
+
+    #[test]
+    fn would_read_numeric_eq_checks_range_membership() {
+        let s = stats("0", "10");
+        assert!(would_read(&Predicate { op: Op::Eq, value: "5".into() }, &s));
+        assert!(!would_read(&Predicate { op: Op::Eq, value: "11".into() }, &s));
+    }
+
+    #[test]
+    fn would_read_numeric_ne_only_skips_singleton_pages_matching_value() {
+        let singleton = stats("5", "5");
+        assert!(!would_read(&Predicate { op: Op::Ne, value: "5".into() }, &singleton));
+        assert!(would_read(&Predicate { op: Op::Ne, value: "6".into() }, &singleton));
+
+        let range = stats("0", "10");
+        assert!(would_read(&Predicate { op: Op::Ne, value: "5".into() }, &range));
+    }
+
+    #[test]
+    fn would_read_falls_back_to_lexicographic_comparison_for_non_numeric_values() {
+        let s = stats("apple", "mango");
+        assert!(would_read(&Predicate { op: Op::Eq, value: "kiwi".into() }, &s));
+        assert!(!would_read(&Predicate { op: Op::Eq, value: "zebra".into() }, &s));
+    }
+
+    #[test]
+    fn bytes_read_skipped_sums_known_sizes_and_ignores_unknown() {
+        let with_size = PageIndexStats {
+            compressed_size: Some(100),
+            ..Default::default()
+        };
+        let without_size = PageIndexStats::default();
+
+        let (read, skipped) =
+            bytes_read_skipped(&[with_size.clone(), without_size.clone()], &[true, true]);
+        assert_eq!((read, skipped), (100, 0));
+
+        let (read, skipped) = bytes_read_skipped(&[with_size, without_size], &[false, false]);
+        assert_eq!((read, skipped), (0, 100));
+    }
+}
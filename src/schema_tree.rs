@@ -0,0 +1,49 @@
+//! Schema-driven navigation: lists a file's leaf columns (path, physical
+//! type, repetition/definition levels) in the same order `ParqFile::current_col`
+//! indexes into, so the column tree panel can show names instead of bare
+//! numbers, and exposes the row group sizes and column chunk compression
+//! codec used to label the column panel.
+
+use parquet::file::metadata::ParquetMetaData;
+
+#[derive(Debug, Clone)]
+pub struct SchemaColumn {
+    pub path: String,
+    pub physical_type: String,
+    pub max_rep_level: i16,
+    pub max_def_level: i16,
+}
+
+/// Flattened, in column-chunk order, list of every leaf column in the file.
+pub fn schema_columns(metadata: &ParquetMetaData) -> Vec<SchemaColumn> {
+    let schema = metadata.file_metadata().schema_descr();
+    (0..schema.num_columns())
+        .map(|i| {
+            let col = schema.column(i);
+            SchemaColumn {
+                path: col.path().string(),
+                physical_type: col.physical_type().to_string(),
+                max_rep_level: col.max_rep_level(),
+                max_def_level: col.max_def_level(),
+            }
+        })
+        .collect()
+}
+
+pub fn column_compression(metadata: &ParquetMetaData, row_group: usize, col: usize) -> String {
+    metadata
+        .row_group(row_group)
+        .column(col)
+        .compression()
+        .to_string()
+}
+
+pub fn row_group_summary(metadata: &ParquetMetaData, row_group: usize) -> String {
+    let rg = metadata.row_group(row_group);
+    format!(
+        "rows:{} compressed:{}b total:{}b",
+        rg.num_rows(),
+        rg.compressed_size(),
+        rg.total_byte_size(),
+    )
+}